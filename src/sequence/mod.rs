@@ -3,8 +3,10 @@
 #[macro_use]
 mod macros;
 
-use internal::IResult;
+use internal::{IResult, Err};
 use error::ParseError;
+use traits::{Offset, Slice};
+use std::ops::RangeTo;
 
 /// Gets an object from the first parser,
 /// then gets another object from the second parser.
@@ -34,10 +36,10 @@ use error::ParseError;
 /// assert_eq!(parser("123"), Err(Err::Error(("123", ErrorKind::Tag))));
 /// # }
 /// ```
-pub fn pair<I, O1, O2, E: ParseError<I>, F, G>(first: F, second: G) -> impl Fn(I) -> IResult<I, (O1, O2), E>
+pub fn pair<I, O1, O2, E: ParseError<I>, F, G>(mut first: F, mut second: G) -> impl FnMut(I) -> IResult<I, (O1, O2), E>
 where
-  F: Fn(I) -> IResult<I, O1, E>,
-  G: Fn(I) -> IResult<I, O2, E>,
+  F: FnMut(I) -> IResult<I, O1, E>,
+  G: FnMut(I) -> IResult<I, O2, E>,
 {
   move |input: I| {
     let (input, o1) = first(input)?;
@@ -49,8 +51,8 @@ where
 #[doc(hidden)]
 pub fn pairc<I, O1, O2, E: ParseError<I>, F, G>(input: I, first: F, second: G) -> IResult<I, (O1, O2), E>
 where
-  F: Fn(I) -> IResult<I, O1, E>,
-  G: Fn(I) -> IResult<I, O2, E>,
+  F: FnMut(I) -> IResult<I, O1, E>,
+  G: FnMut(I) -> IResult<I, O2, E>,
 {
   pair(first, second)(input)
 }
@@ -83,10 +85,10 @@ where
 /// assert_eq!(parser("123"), Err(Err::Error(("123", ErrorKind::Tag))));
 /// # }
 /// ```
-pub fn preceded<I, O1, O2, E: ParseError<I>, F, G>(first: F, second: G) -> impl Fn(I) -> IResult<I, O2, E>
+pub fn preceded<I, O1, O2, E: ParseError<I>, F, G>(mut first: F, mut second: G) -> impl FnMut(I) -> IResult<I, O2, E>
 where
-  F: Fn(I) -> IResult<I, O1, E>,
-  G: Fn(I) -> IResult<I, O2, E>,
+  F: FnMut(I) -> IResult<I, O1, E>,
+  G: FnMut(I) -> IResult<I, O2, E>,
 {
   move |input: I| {
     let (input, _) = first(input)?;
@@ -98,16 +100,16 @@ where
 #[doc(hidden)]
 pub fn precededc<I, O1, O2, E: ParseError<I>, F, G>(input: I, first: F, second: G) -> IResult<I, O2, E>
 where
-  F: Fn(I) -> IResult<I, O1, E>,
-  G: Fn(I) -> IResult<I, O2, E>,
+  F: FnMut(I) -> IResult<I, O1, E>,
+  G: FnMut(I) -> IResult<I, O2, E>,
 {
   preceded(first, second)(input)
 }
 
-pub fn terminated<I, O1, O2, E: ParseError<I>, F, G>(first: F, second: G) -> impl Fn(I) -> IResult<I, O1, E>
+pub fn terminated<I, O1, O2, E: ParseError<I>, F, G>(mut first: F, mut second: G) -> impl FnMut(I) -> IResult<I, O1, E>
 where
-  F: Fn(I) -> IResult<I, O1, E>,
-  G: Fn(I) -> IResult<I, O2, E>,
+  F: FnMut(I) -> IResult<I, O1, E>,
+  G: FnMut(I) -> IResult<I, O2, E>,
 {
   move |input: I| {
     let (input, o1) = first(input)?;
@@ -119,17 +121,17 @@ where
 #[doc(hidden)]
 pub fn terminatedc<I, O1, O2, E: ParseError<I>, F, G>(input: I, first: F, second: G) -> IResult<I, O1, E>
 where
-  F: Fn(I) -> IResult<I, O1, E>,
-  G: Fn(I) -> IResult<I, O2, E>,
+  F: FnMut(I) -> IResult<I, O1, E>,
+  G: FnMut(I) -> IResult<I, O2, E>,
 {
   terminated(first, second)(input)
 }
 
-pub fn separated_pair<I, O1, O2, O3, E: ParseError<I>, F, G, H>(first: F, sep: G, second: H) -> impl Fn(I) -> IResult<I, (O1, O3), E>
+pub fn separated_pair<I, O1, O2, O3, E: ParseError<I>, F, G, H>(mut first: F, mut sep: G, mut second: H) -> impl FnMut(I) -> IResult<I, (O1, O3), E>
 where
-  F: Fn(I) -> IResult<I, O1, E>,
-  G: Fn(I) -> IResult<I, O2, E>,
-  H: Fn(I) -> IResult<I, O3, E>,
+  F: FnMut(I) -> IResult<I, O1, E>,
+  G: FnMut(I) -> IResult<I, O2, E>,
+  H: FnMut(I) -> IResult<I, O3, E>,
 {
   move |input: I| {
     let (input, o1) = first(input)?;
@@ -142,18 +144,18 @@ where
 #[doc(hidden)]
 pub fn separated_pairc<I, O1, O2, O3, E: ParseError<I>, F, G, H>(input: I, first: F, sep: G, second: H) -> IResult<I, (O1, O3), E>
 where
-  F: Fn(I) -> IResult<I, O1, E>,
-  G: Fn(I) -> IResult<I, O2, E>,
-  H: Fn(I) -> IResult<I, O3, E>,
+  F: FnMut(I) -> IResult<I, O1, E>,
+  G: FnMut(I) -> IResult<I, O2, E>,
+  H: FnMut(I) -> IResult<I, O3, E>,
 {
   separated_pair(first, sep, second)(input)
 }
 
-pub fn delimited<I, O1, O2, O3, E: ParseError<I>, F, G, H>(first: F, sep: G, second: H) -> impl Fn(I) -> IResult<I, O2, E>
+pub fn delimited<I, O1, O2, O3, E: ParseError<I>, F, G, H>(mut first: F, mut sep: G, mut second: H) -> impl FnMut(I) -> IResult<I, O2, E>
 where
-  F: Fn(I) -> IResult<I, O1, E>,
-  G: Fn(I) -> IResult<I, O2, E>,
-  H: Fn(I) -> IResult<I, O3, E>,
+  F: FnMut(I) -> IResult<I, O1, E>,
+  G: FnMut(I) -> IResult<I, O2, E>,
+  H: FnMut(I) -> IResult<I, O3, E>,
 {
   move |input: I| {
     let (input, _) = first(input)?;
@@ -166,15 +168,97 @@ where
 #[doc(hidden)]
 pub fn delimitedc<I, O1, O2, O3, E: ParseError<I>, F, G, H>(input: I, first: F, sep: G, second: H) -> IResult<I, O2, E>
 where
-  F: Fn(I) -> IResult<I, O1, E>,
-  G: Fn(I) -> IResult<I, O2, E>,
-  H: Fn(I) -> IResult<I, O3, E>,
+  F: FnMut(I) -> IResult<I, O1, E>,
+  G: FnMut(I) -> IResult<I, O2, E>,
+  H: FnMut(I) -> IResult<I, O3, E>,
 {
   delimited(first, sep, second)(input)
 }
 
+/// An absolute byte range within some shared `base` input, as reported
+/// by [`spanned`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Span {
+  pub start: usize,
+  pub end: usize,
+}
+
+/// Applies a parser and returns the absolute [`Span`] of input it
+/// consumed, measured from `base`, alongside its output.
+///
+/// `base` should be the same untouched input every other `spanned` call
+/// in the document measures from - typically whatever the top-level
+/// parser started with. Because every call is anchored to that one
+/// shared reference rather than to its own entry point, spans stay
+/// correct however deeply `spanned` is nested inside `pair`/`tuple`/
+/// `delimited`, without the caller threading offsets by hand.
+/// # Arguments
+/// * `base` The untouched input to measure positions from.
+/// * `f` The parser to apply.
+/// ```rust
+/// # #[macro_use] extern crate nom;
+/// # use nom::{Err, error::ErrorKind};
+/// use nom::sequence::{spanned, pair, Span};
+/// use nom::bytes::complete::tag;
+/// # fn main() {
+/// let doc = "abefg";
+/// let mut parser = pair(spanned(doc, tag("ab")), spanned(doc, tag("ef")));
+///
+/// let (rest, (first, second)) = parser(doc).unwrap();
+/// assert_eq!(rest, "g");
+/// assert_eq!(first, (Span { start: 0, end: 2 }, "ab"));
+/// assert_eq!(second, (Span { start: 2, end: 4 }, "ef"));
+///
+/// // a parser that succeeds without consuming anything still reports
+/// // an empty-but-valid span
+/// let mut zero_width = spanned::<_, _, (_, ErrorKind), _>(doc, |i| Ok((i, ())));
+/// assert_eq!(zero_width(doc), Ok((doc, (Span { start: 0, end: 0 }, ()))));
+/// # }
+/// ```
+pub fn spanned<I, O, E: ParseError<I>, F>(base: I, mut f: F) -> impl FnMut(I) -> IResult<I, (Span, O), E>
+where
+  I: Offset,
+  F: FnMut(I) -> IResult<I, O, E>,
+{
+  move |input: I| {
+    let start = base.offset(&input);
+    let (remaining, o) = f(input)?;
+    let end = base.offset(&remaining);
+    Ok((remaining, (Span { start, end }, o)))
+  }
+}
+
+/// Applies a parser and returns the slice of input it consumed alongside
+/// its output, instead of just the output.
+/// # Arguments
+/// * `f` The parser to apply.
+/// ```rust
+/// # #[macro_use] extern crate nom;
+/// # use nom::{Err, error::ErrorKind};
+/// use nom::sequence::consumed;
+/// use nom::bytes::complete::tag;
+/// # fn main() {
+/// let mut parser = consumed::<_, _, (_, ErrorKind), _>(tag("abc"));
+///
+/// assert_eq!(parser("abcefg"), Ok(("efg", ("abc", "abc"))));
+/// assert_eq!(parser(""), Err(Err::Error(("", ErrorKind::Tag))));
+/// # }
+/// ```
+pub fn consumed<I, O, E: ParseError<I>, F>(mut f: F) -> impl FnMut(I) -> IResult<I, (I, O), E>
+where
+  I: Clone + Offset + Slice<RangeTo<usize>>,
+  F: FnMut(I) -> IResult<I, O, E>,
+{
+  move |input: I| {
+    let original = input.clone();
+    let (remaining, o) = f(input)?;
+    let index = original.offset(&remaining);
+    Ok((remaining, (original.slice(..index), o)))
+  }
+}
+
 pub trait Tuple<I,O,E> {
-  fn parse(&self, input: I) -> IResult<I,O,E>;
+  fn parse(&mut self, input: I) -> IResult<I,O,E>;
 }
 
 macro_rules! tuple_trait(
@@ -195,10 +279,10 @@ macro_rules! tuple_trait_impl(
   ($($name:ident $ty: ident),+) => (
     impl<
       Input: Clone, $($ty),+ , Error: ParseError<Input>,
-      $($name: Fn(Input) -> IResult<Input, $ty, Error>),+
+      $($name: FnMut(Input) -> IResult<Input, $ty, Error>),+
     > Tuple<Input, ( $($ty),+ ), Error> for ( $($name),+ ) {
 
-      fn parse(&self, input: Input) -> IResult<Input, ( $($ty),+ ), Error> {
+      fn parse(&mut self, input: Input) -> IResult<Input, ( $($ty),+ ), Error> {
         tuple_trait_inner!(0, self, input, (), $($name)+)
 
       }
@@ -227,8 +311,283 @@ macro_rules! tuple_trait_inner(
 tuple_trait!(FnA A, FnB B, FnC C, FnD D, FnE E, FnF F, FnG G, FnH H, FnI I, FnJ J, FnK K, FnL L,
   FnM M, FnN N, FnO O, FnP P, FnQ Q, FnR R, FnS S, FnT T, FnU U);
 
-pub fn tuple<I: Clone, O, E: ParseError<I>, List: Tuple<I,O,E>>(l: List)  -> impl Fn(I) -> IResult<I, O, E> {
+pub fn tuple<I: Clone, O, E: ParseError<I>, List: Tuple<I,O,E>>(mut l: List)  -> impl FnMut(I) -> IResult<I, O, E> {
   move |i: I| {
     l.parse(i)
   }
 }
+
+/// A single element failure recorded by [`tuple_recover`] while it skips
+/// past invalid input instead of aborting the whole sequence.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecoveredError<I, E> {
+  /// index, within the tuple, of the element that failed to parse
+  pub index: usize,
+  /// the error the failing element produced
+  pub error: E,
+  /// the input the synchronization parser skipped over to reach the
+  /// next plausible boundary
+  pub skipped: I,
+}
+
+pub trait TupleRecover<I, O, E, S, D> {
+  fn parse_recover(&mut self, input: I, sync: &mut S, defaults: &mut D, errors: &mut Vec<RecoveredError<I, E>>) -> IResult<I, O, E>;
+}
+
+macro_rules! tuple_trait_recover(
+  ($name1:ident $def1:ident $ty1:ident, $name2:ident $def2:ident $ty2:ident, $($name:ident $def:ident $ty:ident),*) => (
+    tuple_trait_recover!(__impl $name1 $def1 $ty1, $name2 $def2 $ty2; $($name $def $ty),*);
+  );
+  (__impl $($name:ident $def:ident $ty:ident),+; $name1:ident $def1:ident $ty1:ident, $($name2:ident $def2:ident $ty2:ident),*) => (
+    tuple_trait_recover_impl!($($name $def $ty),+);
+    tuple_trait_recover!(__impl $($name $def $ty),+ , $name1 $def1 $ty1; $($name2 $def2 $ty2),*);
+  );
+  (__impl $($name:ident $def:ident $ty:ident),+; $name1:ident $def1:ident $ty1:ident) => (
+    tuple_trait_recover_impl!($($name $def $ty),+);
+    tuple_trait_recover_impl!($($name $def $ty),+, $name1 $def1 $ty1);
+  );
+);
+
+macro_rules! tuple_trait_recover_impl(
+  ($($name:ident $def:ident $ty:ident),+) => (
+    impl<
+      Input: Clone, $($ty),+ , Error: ParseError<Input>,
+      Sync: FnMut(Input) -> IResult<Input, Input, Error>,
+      $($name: FnMut(Input) -> IResult<Input, $ty, Error>),+,
+      $($def: FnMut() -> $ty),+
+    > TupleRecover<Input, ( $($ty),+ ), Error, Sync, ( $($def),+ )> for ( $($name),+ ) {
+
+      fn parse_recover(&mut self, input: Input, sync: &mut Sync, defaults: &mut ( $($def),+ ), errors: &mut Vec<RecoveredError<Input, Error>>) -> IResult<Input, ( $($ty),+ ), Error> {
+        tuple_trait_recover_inner!(0, self, input, sync, defaults, errors, (), $($name)+)
+      }
+    }
+  );
+);
+
+macro_rules! tuple_trait_recover_inner(
+  ($it:tt, $self:expr, $input:expr, $sync:expr, $defaults:expr, $errors:expr, (), $head:ident $($id:ident)+) => ({
+    let (i, o) = tuple_recover_step!($it, $self, $input, $sync, $defaults, $errors);
+    succ!($it, tuple_trait_recover_inner!($self, i, $sync, $defaults, $errors, ( o ), $($id)+))
+  });
+  ($it:tt, $self:expr, $input:expr, $sync:expr, $defaults:expr, $errors:expr, ($($parsed:tt)*), $head:ident $($id:ident)+) => ({
+    let (i, o) = tuple_recover_step!($it, $self, $input, $sync, $defaults, $errors);
+    succ!($it, tuple_trait_recover_inner!($self, i, $sync, $defaults, $errors, ($($parsed)* , o), $($id)+))
+  });
+  ($it:tt, $self:expr, $input:expr, $sync:expr, $defaults:expr, $errors:expr, ($($parsed:tt)*), $head:ident) => ({
+    let (i, o) = tuple_recover_step!($it, $self, $input, $sync, $defaults, $errors);
+    Ok((i, ($($parsed)* , o)))
+  });
+);
+
+macro_rules! tuple_recover_step(
+  ($it:tt, $self:expr, $input:expr, $sync:expr, $defaults:expr, $errors:expr) => ({
+    match $self.$it($input.clone()) {
+      Ok((i, o)) => (i, o),
+      Err(Err::Error(error)) => {
+        let (i, skipped) = $sync($input)?;
+        $errors.push(RecoveredError { index: $it, error, skipped });
+        (i, ($defaults.$it)())
+      }
+      Err(e) => return Err(e),
+    }
+  });
+);
+
+tuple_trait_recover!(FnA DefA A, FnB DefB B, FnC DefC C, FnD DefD D, FnE DefE E, FnF DefF F, FnG DefG G, FnH DefH H,
+  FnI DefI I, FnJ DefJ J, FnK DefK K, FnL DefL L, FnM DefM M, FnN DefN N, FnO DefO O, FnP DefP P,
+  FnQ DefQ Q, FnR DefR R, FnS DefS S, FnT DefT T, FnU DefU U);
+
+/// Applies a list of parsers in sequence like [`tuple`], but recovers
+/// from an element that returns `Err::Error` instead of aborting the
+/// whole sequence: the error is recorded, `sync` skips ahead and the
+/// element's default is substituted. An element that returns
+/// `Err::Failure` or `Err::Incomplete`, or a `sync` that returns any
+/// error, still aborts the whole sequence.
+/// # Arguments
+/// * `l` The list of parsers to apply.
+/// * `defaults` A list, the same shape as `l`, of zero-argument closures
+///   each producing the output to substitute for its element on failure.
+/// * `sync` The parser used to skip past invalid input after a failure.
+/// ```rust
+/// # #[macro_use] extern crate nom;
+/// # use nom::{Err, error::ErrorKind};
+/// use nom::sequence::tuple_recover;
+/// use nom::bytes::complete::tag;
+/// # fn main() {
+/// let sync = |i: &'static str| -> Result<(&'static str, &'static str), Err<(&'static str, ErrorKind)>> {
+///   if i.is_empty() {
+///     Ok((i, i))
+///   } else {
+///     Ok((&i[1..], &i[..1]))
+///   }
+/// };
+///
+/// let mut parser = tuple_recover::<_, _, (_, ErrorKind), _, _, _>(
+///   (tag("abc"), tag("efg")),
+///   (|| "", || ""),
+///   sync,
+/// );
+/// let (rest, (values, errors)) = parser("xefg").unwrap();
+/// assert_eq!(values, ("", "efg"));
+/// assert_eq!(rest, "");
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].index, 0);
+///
+/// // an element returning `Err::Failure` still aborts the whole sequence immediately
+/// let fail = |i: &'static str| -> Result<(&'static str, &'static str), Err<(&'static str, ErrorKind)>> {
+///   Err(Err::Failure((i, ErrorKind::Tag)))
+/// };
+/// let mut aborting = tuple_recover::<_, _, (_, ErrorKind), _, _, _>(
+///   (fail, tag("efg")),
+///   (|| "", || ""),
+///   sync,
+/// );
+/// assert_eq!(aborting("xefg"), Err(Err::Failure(("xefg", ErrorKind::Tag))));
+/// # }
+/// ```
+pub fn tuple_recover<I: Clone, O, E: ParseError<I>, List: TupleRecover<I, O, E, S, D>, S: FnMut(I) -> IResult<I, I, E>, D>(
+  mut l: List,
+  mut defaults: D,
+  mut sync: S,
+) -> impl FnMut(I) -> IResult<I, (O, Vec<RecoveredError<I, E>>), E> {
+  move |input: I| {
+    let mut errors = Vec::new();
+    let (input, o) = l.parse_recover(input, &mut sync, &mut defaults, &mut errors)?;
+    Ok((input, (o, errors)))
+  }
+}
+
+/// An ordered, duplicate-preserving context mapping names to captured
+/// values, as produced by [`tuple_named`].
+///
+/// Insertion order and duplicate labels are both preserved, so a name
+/// bound several times (for instance a repeated identifier in a
+/// whitespace-sensitive grammar) can still be queried by occurrence.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Context<O> {
+  bindings: Vec<(&'static str, O)>,
+}
+
+impl<O> Context<O> {
+  /// Creates an empty context.
+  pub fn new() -> Self {
+    Context { bindings: Vec::new() }
+  }
+
+  /// Returns the most recently bound value for `name`, if any.
+  pub fn get(&self, name: &str) -> Option<&O> {
+    self.bindings.iter().rev().find(|(n, _)| *n == name).map(|(_, o)| o)
+  }
+
+  /// Returns the `n`th value bound to `name`, in insertion order.
+  pub fn get_nth(&self, name: &str, n: usize) -> Option<&O> {
+    self.bindings.iter().filter(|(n, _)| *n == name).map(|(_, o)| o).nth(n)
+  }
+
+  /// Returns every value bound to `name`, in insertion order.
+  pub fn all(&self, name: &str) -> Vec<&O> {
+    self.bindings.iter().filter(|(n, _)| *n == name).map(|(_, o)| o).collect()
+  }
+}
+
+impl<O> Default for Context<O> {
+  fn default() -> Self {
+    Context::new()
+  }
+}
+
+/// Tags a parser with a name so its output can be collected into a
+/// [`Context`] by [`tuple_named`], instead of addressed by its position
+/// in a tuple.
+/// # Arguments
+/// * `name` The label to record this parser's output under.
+/// * `f` The parser to apply.
+/// ```rust
+/// # #[macro_use] extern crate nom;
+/// # use nom::error::ErrorKind;
+/// use nom::sequence::{labeled, tuple_named};
+/// use nom::bytes::complete::tag;
+/// # fn main() {
+/// let mut parser = tuple_named::<_, _, (_, ErrorKind), _>((
+///   labeled("abc", tag("abc")),
+///   labeled("efg", tag("efg")),
+/// ));
+///
+/// let (_, ctx) = parser("abcefg").unwrap();
+/// assert_eq!(ctx.get("abc"), Some(&"abc"));
+/// assert_eq!(ctx.get("efg"), Some(&"efg"));
+/// # }
+/// ```
+pub fn labeled<I, O, E: ParseError<I>, F>(name: &'static str, mut f: F) -> impl FnMut(I) -> IResult<I, (&'static str, O), E>
+where
+  F: FnMut(I) -> IResult<I, O, E>,
+{
+  move |input: I| {
+    f(input).map(|(i, o)| (i, (name, o)))
+  }
+}
+
+pub trait NamedTuple<I, O, E> {
+  fn parse_named(&mut self, input: I) -> IResult<I, Context<O>, E>;
+}
+
+macro_rules! tuple_trait_named(
+  ($name1:ident, $name2: ident, $($name:ident),*) => (
+    tuple_trait_named!(__impl $name1, $name2; $($name),*);
+  );
+  (__impl $($name:ident),+; $name1:ident, $($name2:ident),*) => (
+    tuple_trait_named_impl!($($name),+);
+    tuple_trait_named!(__impl $($name),+ , $name1; $($name2),*);
+  );
+  (__impl $($name:ident),+; $name1:ident) => (
+    tuple_trait_named_impl!($($name),+);
+    tuple_trait_named_impl!($($name),+, $name1);
+  );
+);
+
+macro_rules! tuple_trait_named_impl(
+  ($($name:ident),+) => (
+    impl<
+      Input: Clone, Output, Error: ParseError<Input>,
+      $($name: FnMut(Input) -> IResult<Input, (&'static str, Output), Error>),+
+    > NamedTuple<Input, Output, Error> for ( $($name),+ ) {
+
+      fn parse_named(&mut self, input: Input) -> IResult<Input, Context<Output>, Error> {
+        let mut ctx = Context::new();
+        tuple_trait_named_inner!(0, self, input, ctx, $($name)+)
+      }
+    }
+  );
+);
+
+macro_rules! tuple_trait_named_inner(
+  ($it:tt, $self:expr, $input:expr, $ctx:expr, $head:ident $($id:ident)+) => ({
+    let (i, (name, o)) = $self.$it($input.clone())?;
+    $ctx.bindings.push((name, o));
+
+    succ!($it, tuple_trait_named_inner!($self, i, $ctx, $($id)+))
+  });
+  ($it:tt, $self:expr, $input:expr, $ctx:expr, $head:ident) => ({
+    let (i, (name, o)) = $self.$it($input.clone())?;
+    $ctx.bindings.push((name, o));
+
+    Ok((i, $ctx))
+  });
+);
+
+tuple_trait_named!(FnA, FnB, FnC, FnD, FnE, FnF, FnG, FnH, FnI, FnJ, FnK, FnL,
+  FnM, FnN, FnO, FnP, FnQ, FnR, FnS, FnT, FnU);
+
+/// Applies a list of [`labeled`] parsers in sequence, like [`tuple`],
+/// but collects their outputs into an ordered [`Context`] instead of a
+/// positional tuple.
+///
+/// Every element must produce the same output type, since they are all
+/// recorded in the same context.
+/// # Arguments
+/// * `l` The list of labeled parsers to apply.
+pub fn tuple_named<I: Clone, O, E: ParseError<I>, List: NamedTuple<I, O, E>>(mut l: List) -> impl FnMut(I) -> IResult<I, Context<O>, E> {
+  move |i: I| {
+    l.parse_named(i)
+  }
+}